@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Runtime API definition for the price-fetch pallet.
+///
+/// This exposes the data a client needs to query the latest consensus price
+/// for an asset without decoding raw pallet storage by hand.
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PriceFetchApi<AssetId, Moment, Price, BlockNumber> where
+		AssetId: Codec,
+		Moment: Codec,
+		Price: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the last aggregated `(Moment, Price)` stored for `asset_id`, if any.
+		fn price_of(asset_id: AssetId) -> Option<(Moment, Price)>;
+
+		/// Returns `(asset_id, end_fetching_at)` for every fetcher currently running.
+		fn active_fetchers() -> Vec<(AssetId, BlockNumber)>;
+
+		/// Returns the number of samples collected so far for `asset_id`.
+		fn samples_collected(asset_id: AssetId) -> u32;
+	}
+}
@@ -0,0 +1,98 @@
+//! RPC interface for the price-fetch pallet.
+///
+/// Modeled on `pallet_transaction_payment_rpc`: a thin `jsonrpc-derive` facade
+/// over the `PriceFetchApi` runtime API, so a client can query the latest
+/// consensus price with a single call instead of decoding raw storage.
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result as RpcResult};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_std::vec::Vec;
+
+pub use price_fetch_rpc_runtime_api::PriceFetchApi as PriceFetchRuntimeApi;
+
+#[rpc]
+pub trait PriceFetchApi<BlockHash, AssetId, Moment, Price, BlockNumber> {
+	/// Returns the last aggregated `(Moment, Price)` stored for `asset_id`, if any.
+	#[rpc(name = "priceFetch_priceOf")]
+	fn price_of(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<(Moment, Price)>>;
+
+	/// Returns `(asset_id, end_fetching_at)` for every fetcher currently running.
+	#[rpc(name = "priceFetch_activeFetchers")]
+	fn active_fetchers(&self, at: Option<BlockHash>) -> RpcResult<Vec<(AssetId, BlockNumber)>>;
+}
+
+/// A struct that implements the `PriceFetchApi`.
+pub struct PriceFetch<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> PriceFetch<C, B> {
+	/// Create new `PriceFetch` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AssetId, Moment, Price, BlockNumber>
+	PriceFetchApi<<Block as BlockT>::Hash, AssetId, Moment, Price, BlockNumber> for PriceFetch<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PriceFetchRuntimeApi<Block, AssetId, Moment, Price, BlockNumber>,
+	AssetId: Codec,
+	Moment: Codec,
+	Price: Codec,
+	BlockNumber: Codec,
+{
+	fn price_of(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<(Moment, Price)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.price_of(&at, asset_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query price.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn active_fetchers(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(AssetId, BlockNumber)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.active_fetchers(&at).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query active fetchers.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
+
+// NOTE: wiring this in happens in `node/rpc`'s `create_full`, the same place
+// `pallet_transaction_payment_rpc::TransactionPayment` gets added to the `io`
+// returned for the node's RPC extensions. This tree doesn't carry a `node`
+// crate to edit, so this is the extension point for when it does:
+//
+// io.extend_with(PriceFetchApi::to_delegate(PriceFetch::new(client.clone())));
@@ -0,0 +1,96 @@
+//! Price-fetch pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+
+use crate::Module as PriceFetch;
+
+const SEED: u32 = 0;
+
+//NOTE: `submit_new_price_unsigned` isn't benchmarked here: producing a valid
+//`T::AuthorityId` signature needs a concrete crypto pair, which isn't
+//reachable generically from this trait-bounded context. Its weight in
+//`weights.rs` reuses the `submit_new_price` estimate as a placeholder until
+//it gets its own benchmark.
+
+/// Fill `FetchedPrices` for `asset_id` with `s` samples authored by distinct accounts.
+fn setup_prices<T: Trait>(asset_id: T::AssetId, s: u32) {
+	for i in 0..s {
+		let author = account::<T::AccountId>("sample", i, SEED);
+		let price = FetchedPrice {
+			price: Price::from(1),
+			time: b"0".to_vec(),
+			asset_id,
+			source_id: 0,
+			author,
+		};
+		<FetchedPrices<T>>::mutate(asset_id, |prices| prices.push(price));
+	}
+}
+
+fn setup_sources<T: Trait>(asset_id: T::AssetId, s: u32) -> Vec<SourceConfig> {
+	let sources: Vec<SourceConfig> = (0..s)
+		.map(|i| SourceConfig {
+			source_id: i as u8,
+			url: SYMBOLS[0].1.to_vec(),
+			price_path: b"/Price".to_vec(),
+			decimals: 0,
+		})
+		.collect();
+	<Sources<T>>::insert(
+		asset_id,
+		BoundedVec::<SourceConfig, T::MaxSourcesPerAsset>::try_from(sources.clone()).unwrap_or_default(),
+	);
+	sources
+}
+
+benchmarks! {
+	set_sources {
+		let s in 0 .. T::MaxSourcesPerAsset::get();
+
+		let asset_id: T::AssetId = Default::default();
+		let sources = setup_sources::<T>(asset_id, s);
+	}: _(RawOrigin::Root, asset_id, sources)
+	verify {
+		assert_eq!(<Sources<T>>::get(asset_id).len(), s as usize);
+	}
+
+	start_fetcher {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = Default::default();
+		setup_sources::<T>(asset_id, 1);
+	}: _(RawOrigin::Signed(caller), asset_id, T::MaxFetchDuration::get(), AggregationKind::Mean)
+	verify {
+		assert!(<Fetchers<T>>::contains_key(&asset_id));
+	}
+
+	submit_new_price {
+		let s in 0 .. 1000;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = Default::default();
+		setup_sources::<T>(asset_id, 1);
+		PriceFetch::<T>::start_fetcher(RawOrigin::Signed(caller.clone()).into(), asset_id, T::MaxFetchDuration::get(), AggregationKind::Mean)?;
+		setup_prices::<T>(asset_id, s);
+	}: _(RawOrigin::Signed(caller), asset_id, 0, Price::from(1), b"0".to_vec())
+	verify {
+		assert_eq!(<FetchedPrices<T>>::decode_len(asset_id).unwrap_or(0), (s + 1) as usize);
+	}
+
+	submit_new_avg_price {
+		let s in 1 .. 1000;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = Default::default();
+		setup_sources::<T>(asset_id, 1);
+		PriceFetch::<T>::start_fetcher(RawOrigin::Signed(caller.clone()).into(), asset_id, T::MaxFetchDuration::get(), AggregationKind::Mean)?;
+		setup_prices::<T>(asset_id, s.max(T::MinSampleCount::get()));
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert!(<AvgPrices<T>>::contains_key(&asset_id));
+	}
+}
@@ -3,8 +3,10 @@
 /// WIP - price fetch pallet
 /// The target of this pallet is to get a reliable price point on request
 ///
-/// The process of fetching and calculating the price starts by calling start_fetcher(symbol, duration)
-/// where symbol is a ticker of fetched asset and duration is number of blocks for which we fetch the price.
+/// The process of fetching and calculating the price starts by calling start_fetcher(asset_id, duration, kind)
+/// where asset_id identifies the fetched asset, duration is number of blocks for which we fetch the price,
+/// and kind selects how the collected samples are later aggregated (arithmetic mean, median, or a
+/// time-weighted average).
 /// This call should cost enough to cover the costs of all subsequent actions done by the pallet.
 ///
 /// After start_fetcher is called, validators should fetch the price and post it as a transaction.
@@ -15,21 +17,33 @@
 ///
 /// We assume proof of stake environment, thus we can be sure this process is secured by validators stake.
 ///
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, FullCodec};
 use frame_support::{
-	debug, decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get,
+	debug, decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
+	traits::{Currency, Get, ReservableCurrency},
+	weights::{DispatchClass, Pays},
+	BoundedVec, Parameter,
 };
 
 use frame_system::{
-	self as system, ensure_signed,
-	offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+	self as system, ensure_none, ensure_root, ensure_signed,
+	offchain::{
+		AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
+		SigningTypes,
+	},
 };
 
-use alt_serde::{Deserialize, Deserializer};
-
 use primitives::Price;
+use scale_info::TypeInfo;
 use sp_core::crypto::KeyTypeId;
-use sp_runtime::offchain::{http, Duration};
+use sp_runtime::{
+	offchain::{http, Duration},
+	traits::{IdentifyAccount, Member, SaturatedConversion, Saturating, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+	},
+	FixedPointNumber, RuntimeDebug,
+};
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -38,62 +52,175 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-pub type Symbol = Vec<u8>;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
 
-//TODO: this should be a param to start_fetcher(symbol, duration) function
-const SYM: &[u8; 3] = b"ETH";
+/// One API an asset's price can be polled from.
+///
+/// Several of these can be configured per asset (see `Sources`), letting one
+/// fetcher poll several independent APIs instead of being pinned to a single
+/// hard-coded URL. Every `FetchedPrice` produced from a source is tagged with
+/// its `source_id` so cross-source disagreement can be detected later.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SourceConfig {
+	/// Identifies this source within its asset's source list.
+	pub source_id: u8,
+	/// The API endpoint to fetch the price from.
+	pub url: Vec<u8>,
+	/// JSON pointer (RFC 6901) to the price field in the response body, e.g. `/Price`.
+	pub price_path: Vec<u8>,
+	/// Number of decimal places the raw price value at `price_path` is expressed in.
+	/// `0` means the value is already a human-readable price (e.g. DIA's `Price` field).
+	pub decimals: u8,
+}
+
+/// Example source table for `ETH`, kept as a reference for runtimes seeding
+/// `Sources` via `set_sources`; the benchmarks build their sources from it.
 pub const SYMBOLS: [(&[u8], &[u8]); 1] = [(b"ETH", b"https://api.diadata.org/v1/quotation/ETH")];
 
-// Specifying serde path as `alt_serde`
-// ref: https://serde.rs/container-attrs.html#crate
-#[serde(crate = "alt_serde")]
-#[derive(Deserialize, Encode, Decode, Default, Clone, PartialEq, Debug)]
-pub struct DiaPriceRecord {
-	#[serde(rename(deserialize = "Price"))]
-	#[serde(deserialize_with = "de_float_to_price")]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo)]
+pub struct FetchedPrice<AccountId, AssetId> {
 	price: Price,
-	#[serde(deserialize_with = "de_string_to_bytes")]
-	#[serde(rename(deserialize = "Time"))]
 	time: Vec<u8>,
-	#[serde(deserialize_with = "de_string_to_bytes")]
-	#[serde(rename(deserialize = "Symbol"))]
-	symbol: Symbol,
+	asset_id: AssetId,
+	/// `source_id` of the `SourceConfig` this sample was fetched from.
+	source_id: u8,
+	author: AccountId,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
-pub struct FetchedPrice<AccountId> {
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo)]
+pub struct Fetcher<AccountId, AssetId, BlockNumber, Balance> {
+	asset_id: AssetId,
+	end_fetching_at: BlockNumber,
+	/// Account that paid `deposit` to start this fetcher; refunded once it completes.
+	depositor: AccountId,
+	deposit: Balance,
+	/// How the collected samples are combined into the final stored price.
+	kind: AggregationKind,
+}
+
+/// How a fetcher's collected samples are combined into the final stored price.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum AggregationKind {
+	/// Arithmetic mean of the non-outlier samples.
+	Mean,
+	/// Median of the non-outlier samples.
+	Median,
+	/// Time-weighted average: each sample is weighted by how long it stayed
+	/// "in effect" until the next one.
+	Twap,
+}
+
+impl Default for AggregationKind {
+	fn default() -> Self {
+		AggregationKind::Mean
+	}
+}
+
+/// Payload signed offchain by a validator's session key and submitted as an
+/// unsigned transaction via `submit_new_price_unsigned`, so reporting a price
+/// doesn't require the validator to hold a funded account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PricePayload<Public, BlockNumber, AssetId> {
+	block_number: BlockNumber,
+	asset_id: AssetId,
+	source_id: u8,
 	price: Price,
 	time: Vec<u8>,
-	symbol: Symbol,
-	author: AccountId,
+	public: Public,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
-pub struct Fetcher<BlockNumber> {
-	symbol: Symbol,
-	url: Vec<u8>,
-	end_fetching_at: BlockNumber,
+impl<T: Trait> SignedPayload<T> for PricePayload<T::Public, T::BlockNumber, T::AssetId> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// Penalizes an account for submitting a price flagged as an outlier during
+/// aggregation. Kept as a pallet-local trait since there's no shared slashing
+/// primitive in scope here; a runtime can wire this to `pallet_staking` or
+/// similar.
+pub trait Slashing<AccountId> {
+	fn slash(who: &AccountId);
+}
+
+impl<AccountId> Slashing<AccountId> for () {
+	fn slash(_who: &AccountId) {}
 }
 
-pub fn de_string_to_bytes<'de, D>(de: D) -> Result<Vec<u8>, D::Error>
-where
-	D: Deserializer<'de>,
-{
-	let s: &str = Deserialize::deserialize(de)?;
-	Ok(s.as_bytes().to_vec())
+/// Supplies the public keys of the current session's authorities, checked by
+/// `ValidateUnsigned` against a `submit_new_price_unsigned` payload's signer.
+/// Kept as a pallet-local trait, same rationale as `Slashing`: no session-set
+/// primitive is assumed here, but a runtime composing `pallet_session` (or
+/// `pallet_im_online`-style rotated keys) wires it in, so the authority set
+/// is always live rather than something a root call has to remember to keep
+/// in sync.
+pub trait ValidatorSet<Public> {
+	fn authorities() -> Vec<Public>;
 }
 
-pub fn de_float_to_price<'de, D>(de: D) -> Result<Price, D::Error>
-where
-	D: Deserializer<'de>,
-{
-	let fp: f64 = Deserialize::deserialize(de)?;
-
-	//TODO: CONST -> DECIMAL PLACES FOR PRICE.
-	//		This will depend on the type used in our case sp_runtime::FixedU128
-	//TODO: Make sure this doesn't overflow
-	let int = (fp * (1_000_000_000_000_000_000_f64)) as u128;
-	Ok(Price::from_inner(int))
+impl<Public> ValidatorSet<Public> for () {
+	fn authorities() -> Vec<Public> {
+		Vec::new()
+	}
+}
+
+/// Number of MAD-scaled deviations from the median a sample may differ by
+/// before it's flagged as an outlier.
+const OUTLIER_K: u128 = 3;
+
+/// Numerator/denominator of `1.4826`, the constant that scales a median
+/// absolute deviation (MAD) into an approximation of a standard deviation.
+const MAD_SCALE_NUMERATOR: u128 = 14_826;
+const MAD_SCALE_DENOMINATOR: u128 = 10_000;
+
+/// Median of `values`, sorted in place. Averages the two middle elements for
+/// an even-length slice. Returns zero for an empty slice, reachable when a
+/// runtime configures `MinSampleCount` to `0`.
+fn median(values: &mut Vec<Price>) -> Price {
+	values.sort();
+	let len = values.len();
+	if len == 0 {
+		return Price::from(0);
+	}
+	if len % 2 == 1 {
+		values[len / 2]
+	} else {
+		(values[len / 2 - 1] + values[len / 2]) / Price::from(2)
+	}
+}
+
+fn abs_diff(a: Price, b: Price) -> Price {
+	if a >= b {
+		a - b
+	} else {
+		b - a
+	}
+}
+
+/// Extract the price at `price_path` (an RFC 6901 JSON pointer) from a response
+/// `body`, scaling it from `decimals` decimal places up to `Price`'s native
+/// fixed-point representation.
+///
+/// `decimals == 0` means the value is already a human-readable price (e.g.
+/// DIA's `Price` field), matching the scaling the pallet always used.
+///
+/// //TODO: Make sure this doesn't overflow
+pub fn extract_price(body: &str, price_path: &[u8], decimals: u8) -> Option<Price> {
+	let path = sp_std::str::from_utf8(price_path).ok()?;
+	let value: serde_json::Value = serde_json::from_str(body).ok()?;
+	let raw = value.pointer(path)?;
+
+	let raw_price: f64 = match raw.as_f64() {
+		Some(n) => n,
+		None => raw.as_str()?.parse().ok()?,
+	};
+
+	let scale = 10_f64.powi(18_i32.saturating_sub(decimals as i32));
+	Some(Price::from_inner((raw_price * scale) as u128))
 }
 
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"pocw");
@@ -121,6 +248,9 @@ pub mod crypto {
 	}
 }
 
+/// Balance type used for the `start_fetcher` deposit, taken from the configured `Currency`.
+pub type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
 /// This pallet's configuration trait
 pub trait Trait: CreateSignedTransaction<Call<Self>> + pallet_timestamp::Trait + system::Trait {
 	/// The identifier type for an offchain worker.
@@ -134,29 +264,80 @@ pub trait Trait: CreateSignedTransaction<Call<Self>> + pallet_timestamp::Trait +
 
 	/// Grace period between submitting prices. Submit price only every GracePeriod block
 	type GracePeriod: Get<Self::BlockNumber>;
+
+	/// The asset identifier this pallet fetches oracle prices for. Bound to
+	/// HydraDX's own on-chain asset ids rather than stringly-typed tickers.
+	type AssetId: Parameter + Member + Copy + Eq + FullCodec + TypeInfo + Default;
+
+	/// Maximum number of sources that can be configured for a single asset.
+	type MaxSourcesPerAsset: Get<u32>;
+
+	/// Maximum number of blocks a fetcher is allowed to run for.
+	type MaxFetchDuration: Get<Self::BlockNumber>;
+
+	/// Currency used to charge the `start_fetcher` deposit.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// Deposit charged per block of `start_fetcher` duration, to cover the cost
+	/// of the offchain fetching and aggregation it triggers.
+	type DepositPerBlock: Get<BalanceOf<Self>>;
+
+	/// Minimum number of samples required to close out a fetcher. Aggregation
+	/// fails (and the fetcher stays open for more samples) below this count.
+	type MinSampleCount: Get<u32>;
+
+	/// Used to penalize accounts whose submitted price is flagged as an
+	/// outlier during aggregation.
+	type Slashing: Slashing<Self::AccountId>;
+
+	/// Source of the current session's authority keys, checked by
+	/// `ValidateUnsigned` against `submit_new_price_unsigned` payloads.
+	type ValidatorSet: ValidatorSet<Self::Public>;
+
+	/// Priority assigned to `submit_new_price_unsigned` transactions in
+	/// `ValidateUnsigned`.
+	type UnsignedPriority: Get<TransactionPriority>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
 	trait Store for Module<T: Trait> as PriceFetch {
 		///Map of currently running fetchers
-		Fetchers get(fn fetcher): map hasher(identity) Vec<u8> => Fetcher<T::BlockNumber>;
+		Fetchers get(fn fetcher): map hasher(blake2_128_concat) T::AssetId => Fetcher<T::AccountId, T::AssetId, T::BlockNumber, BalanceOf<T>>;
 
-		///Map of raw fetched_prices from oracle. Key is hash of symbol e.g hash('ETH')
-		FetchedPrices get(fn fetched_prices): map hasher(identity) Vec<u8> => Vec<FetchedPrice<T::AccountId>>;
+		///Map of raw fetched_prices from oracle, keyed by asset id
+		FetchedPrices get(fn fetched_prices): map hasher(blake2_128_concat) T::AssetId => Vec<FetchedPrice<T::AccountId, T::AssetId>>;
 
 		///Map of aggregated prices
-		AvgPrices get(fn avg_price): map hasher(identity) Vec<u8> => (T::Moment, Price, T::AccountId);
+		AvgPrices get(fn avg_price): map hasher(blake2_128_concat) T::AssetId => (T::Moment, Price, T::AccountId);
+
+		///Sources configured per asset, polled by the offchain worker while a fetcher is running
+		Sources get(fn sources): map hasher(blake2_128_concat) T::AssetId => BoundedVec<SourceConfig, T::MaxSourcesPerAsset>;
+
+		///Accounts flagged and slashed as price outliers in the most recent aggregation round
+		Offenders get(fn offenders): map hasher(blake2_128_concat) T::AssetId => Vec<T::AccountId>;
 	}
 }
 
 decl_error! {
 	pub enum Error for Module<T: Trait> {
-		//Fetcher for required symbol is already running
+		//Fetcher for required asset is already running
 		FetcherAlreadyExist,
-		//start fetcher for unsupported symbol (currency/token, e.g ETH
+		//start fetcher for an asset with no configured source
 		SymbolNotFound,
 
 		FetcherNotFound,
+
+		//start_fetcher called with a duration above T::MaxFetchDuration
+		FetchDurationTooLong,
+
+		//set_sources called with more sources than T::MaxSourcesPerAsset
+		TooManySources,
+
+		//submit_new_avg_price called with fewer than T::MinSampleCount samples collected
+		NotEnoughSamples,
 	}
 }
 
@@ -166,16 +347,19 @@ decl_event!(
 		Moment = <T as pallet_timestamp::Trait>::Moment,
 		AccountId = <T as frame_system::Trait>::AccountId,
 		Price = Price,
-		Symbol = Symbol,
+		AssetId = <T as Trait>::AssetId,
 	{
 		//New fetcher was initialized
-		NewFetcher(AccountId, Symbol, Moment),
+		NewFetcher(AccountId, AssetId, Moment),
 
-		//New price point was saved from symbol
-		NewPricePoint(AccountId, Symbol, Moment, Price),
+		//New price point was saved for asset
+		NewPricePoint(AccountId, AssetId, Moment, Price),
 
 		//New avg price was calculated and old fetcher was destroyed
-		NewAvgPrice(AccountId, Symbol, Moment, Price),
+		NewAvgPrice(AccountId, AssetId, Moment, Price),
+
+		//A submitted price was flagged as a MAD outlier and its author slashed
+		AnomalyDetected(AccountId, AssetId, Price),
 	}
 );
 
@@ -187,66 +371,134 @@ decl_module! {
 
 		fn deposit_event() = default;
 
-		///Start fetching price for 600 blocks
-		//TODO: add fetched duration and symbol
-		#[weight = 0]
-		pub fn start_fetcher(origin) -> DispatchResult {
+		///Configure the sources polled for `asset_id`. Root-only: source urls
+		///and JSON paths are trusted inputs, not something callers should set
+		///for arbitrary assets.
+		#[weight = T::WeightInfo::set_sources(sources.len() as u32)]
+		pub fn set_sources(origin, asset_id: T::AssetId, sources: Vec<SourceConfig>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let bounded: BoundedVec<SourceConfig, T::MaxSourcesPerAsset> =
+				sources.try_into().map_err(|_| Error::<T>::TooManySources)?;
+			<Sources<T>>::insert(asset_id, bounded);
+
+			Ok(())
+		}
+
+		///Start fetching the price of `asset_id` for `duration` blocks, aggregating the
+		///collected samples according to `kind` once the fetcher ends.
+		#[weight = T::WeightInfo::start_fetcher()]
+		pub fn start_fetcher(origin, asset_id: T::AssetId, duration: T::BlockNumber, kind: AggregationKind) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(!<Fetchers<T>>::contains_key(&SYM.to_vec()), Error::<T>::FetcherAlreadyExist);
+			ensure!(!<Fetchers<T>>::contains_key(&asset_id), Error::<T>::FetcherAlreadyExist);
+			ensure!(duration <= T::MaxFetchDuration::get(), Error::<T>::FetchDurationTooLong);
+			ensure!(!<Sources<T>>::get(&asset_id).is_empty(), Error::<T>::SymbolNotFound);
 
-			//TODO: duration should be param of function
-			let end_at = <system::Module<T>>::block_number() + T::BlockNumber::from(600); //600 blocs is 1hour at 1 block/6s
-			let url = match SYMBOLS.iter().find(|(s, _)| s == SYM) {
-				Some (p) => Ok(p.1),
-				None => Err(Error::<T>::SymbolNotFound)
-			}?;
+			let end_at = <system::Module<T>>::block_number() + duration;
+
+			let deposit = T::DepositPerBlock::get().saturating_mul(duration.saturated_into());
+			T::Currency::reserve(&who, deposit)?;
 
 			let new_fetcher = Fetcher {
-				symbol: SYM.to_vec(),
+				asset_id,
 				end_fetching_at: end_at,
-				url: url.to_vec()
+				depositor: who.clone(),
+				deposit,
+				kind,
 			};
-			<Fetchers<T>>::insert(SYM.to_vec(), new_fetcher);
+			<Fetchers<T>>::insert(asset_id, new_fetcher);
 
 			let now = <pallet_timestamp::Module<T>>::get();
-			Self::deposit_event(RawEvent::NewFetcher(who, SYM.to_vec(), now));
+			Self::deposit_event(RawEvent::NewFetcher(who, asset_id, now));
 			Ok(())
 		}
 
-		#[weight = 0]
-		pub fn submit_new_price(origin, price_record: DiaPriceRecord) -> DispatchResult {
+		#[weight = T::WeightInfo::submit_new_price(<FetchedPrices<T>>::decode_len(asset_id).unwrap_or(0) as u32)]
+		pub fn submit_new_price(origin, asset_id: T::AssetId, source_id: u8, price: Price, time: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(<Fetchers<T>>::contains_key(&price_record.symbol), Error::<T>::FetcherNotFound);
+			ensure!(<Fetchers<T>>::contains_key(&asset_id), Error::<T>::FetcherNotFound);
 
 			let new_price = FetchedPrice {
-				price: price_record.price,
-				time: price_record.time,
-				symbol: price_record.symbol.clone(),
+				price,
+				time,
+				asset_id,
+				source_id,
 				author: who.clone()
 			};
 
 			Self::add_new_price_to_list(new_price);
 
 			let now = <pallet_timestamp::Module<T>>::get();
-			Self::deposit_event(RawEvent::NewPricePoint(who, price_record.symbol, now, price_record.price));
+			Self::deposit_event(RawEvent::NewPricePoint(who, asset_id, now, price));
 
 			Ok(())
 		}
 
-		#[weight = 0]
-		pub fn submit_new_avg_price(origin, symbol: Symbol, avg_price:Price) -> DispatchResult {
+		///Report a price via an unsigned transaction whose payload is signed by a
+		///validator's offchain-worker key, so reporting doesn't require a funded
+		///account. Validity (signature, active fetcher, rate limit) is checked in
+		///`ValidateUnsigned` before this ever runs.
+		#[weight = (
+			T::WeightInfo::submit_new_price_unsigned(<FetchedPrices<T>>::decode_len(payload.asset_id).unwrap_or(0) as u32),
+			DispatchClass::Normal,
+			Pays::No
+		)]
+		pub fn submit_new_price_unsigned(
+			origin,
+			payload: PricePayload<T::Public, T::BlockNumber, T::AssetId>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			ensure!(<Fetchers<T>>::contains_key(&payload.asset_id), Error::<T>::FetcherNotFound);
+
+			let author = payload.public.clone().into_account();
+			let new_price = FetchedPrice {
+				price: payload.price,
+				time: payload.time.clone(),
+				asset_id: payload.asset_id,
+				source_id: payload.source_id,
+				author: author.clone(),
+			};
+
+			Self::add_new_price_to_list(new_price);
+
+			let now = <pallet_timestamp::Module<T>>::get();
+			Self::deposit_event(RawEvent::NewPricePoint(author, payload.asset_id, now, payload.price));
+
+			Ok(())
+		}
+
+		#[weight = T::WeightInfo::submit_new_avg_price(<FetchedPrices<T>>::decode_len(asset_id).unwrap_or(0) as u32)]
+		pub fn submit_new_avg_price(origin, asset_id: T::AssetId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			ensure!(<Fetchers<T>>::contains_key(&asset_id), Error::<T>::FetcherNotFound);
+
+			let price_points = <FetchedPrices<T>>::get(asset_id);
+			ensure!(price_points.len() as u32 >= T::MinSampleCount::get(), Error::<T>::NotEnoughSamples);
+
+			//delete finished fetcher before aggregating so its `kind` is known; its deposit is refunded below
+			let old_fetcher = <Fetchers<T>>::take(asset_id);
+			let (avg_price, offenders) = Self::aggregate(old_fetcher.kind, &price_points);
+
 			let now = <pallet_timestamp::Module<T>>::get();
-			<AvgPrices<T>>::insert(symbol.clone(), (now, avg_price, who.clone()));
+			for (offender, reported_price) in &offenders {
+				T::Slashing::slash(offender);
+				Self::deposit_event(RawEvent::AnomalyDetected(offender.clone(), asset_id, *reported_price));
+			}
+			<Offenders<T>>::insert(asset_id, offenders.into_iter().map(|(offender, _)| offender).collect::<Vec<_>>());
 
-			//delete finished fetcher and remove old data
-			let _old_fetcher = <Fetchers<T>>::take(symbol.clone());
-			let _old_prices = <FetchedPrices<T>>::take(symbol.clone());
+			<AvgPrices<T>>::insert(asset_id, (now, avg_price, who.clone()));
+
+			if !old_fetcher.deposit.is_zero() {
+				T::Currency::unreserve(&old_fetcher.depositor, old_fetcher.deposit);
+			}
+			let _old_prices = <FetchedPrices<T>>::take(asset_id);
 
-			Self::deposit_event(RawEvent::NewAvgPrice(who, symbol, now, avg_price));
+			Self::deposit_event(RawEvent::NewAvgPrice(who, asset_id, now, avg_price));
 
 			Ok(())
 		}
@@ -265,8 +517,8 @@ decl_module! {
 						debug::error!("Error: {}", e);
 					}
 				} else if block_number % T::GracePeriod::get() == 0.into() {
-					//TASK II.: Fetch and submit price
-					if let Err(e) = Self::fetch_price_and_submit(f) {
+					//TASK II.: Fetch and submit price from every configured source
+					if let Err(e) = Self::fetch_price_and_submit(f, block_number) {
 						debug::error!("Error: {}", e);
 					}
 				}
@@ -280,38 +532,54 @@ decl_module! {
 /// This greatly helps with error messages, as the ones inside the macro
 /// can sometimes be hard to debug.
 impl<T: Trait> Module<T> {
-	fn add_new_price_to_list(price: FetchedPrice<T::AccountId>) {
-		<FetchedPrices<T>>::mutate(price.symbol.clone(), |prices| {
+	/// Last aggregated `(Moment, Price)` stored for `asset_id`, if any.
+	///
+	/// Backs the `PriceFetchApi::price_of` runtime API.
+	pub fn price_of(asset_id: T::AssetId) -> Option<(T::Moment, Price)> {
+		if !<AvgPrices<T>>::contains_key(&asset_id) {
+			return None;
+		}
+		let (time, price, _author) = <AvgPrices<T>>::get(asset_id);
+		Some((time, price))
+	}
+
+	/// `(asset_id, end_fetching_at)` for every fetcher currently running.
+	///
+	/// Backs the `PriceFetchApi::active_fetchers` runtime API.
+	pub fn active_fetchers() -> Vec<(T::AssetId, T::BlockNumber)> {
+		<Fetchers<T>>::iter().map(|(asset_id, f)| (asset_id, f.end_fetching_at)).collect()
+	}
+
+	/// Number of samples collected so far for `asset_id`.
+	///
+	/// Backs the `PriceFetchApi::samples_collected` runtime API.
+	pub fn samples_collected(asset_id: T::AssetId) -> u32 {
+		<FetchedPrices<T>>::decode_len(asset_id).unwrap_or(0) as u32
+	}
+
+	fn add_new_price_to_list(price: FetchedPrice<T::AccountId, T::AssetId>) {
+		<FetchedPrices<T>>::mutate(price.asset_id, |prices| {
 			prices.push(price);
 		});
 	}
 
 	//NOTE: consider move to onf_finalize
-	fn calc_and_submit_avg_price(fetcher: Fetcher<T::BlockNumber>) -> Result<(), &'static str> {
+	fn calc_and_submit_avg_price(
+		fetcher: Fetcher<T::AccountId, T::AssetId, T::BlockNumber, BalanceOf<T>>,
+	) -> Result<(), &'static str> {
 		let signer = Signer::<T, T::AuthorityId>::all_accounts();
 		if !signer.can_sign() {
 			return Err("No local accounts available. Consider adding one via `author_insertKey` RPC.");
 		}
 
-		//TODO: add minimum samples count e.g avg price will be computed only if 100 samples was
-		//submitted. Otherwise it will fail
-		let price_points = <FetchedPrices<T>>::get(fetcher.symbol.clone());
-
-		//TODO: clean up invalid prices
-		let mut sum: Price = Price::from(0);
-		let mut samples_count = Price::from(0);
-		price_points.iter().for_each(|pp| {
-			sum = sum + pp.price;
-			samples_count = samples_count + Price::from(1);
-		});
-
-		let avg_price = sum / samples_count;
-
+		//NOTE: the average and any slashing are computed on-chain, by
+		//`submit_new_avg_price` itself, so every validator agrees on who gets
+		//slashed; this just triggers that round to close.
 		let results = signer.send_signed_transaction(|_account| {
 			// Received price is wrapped into a call to `submit_price` public function of this pallet.
 			// This means that the transaction, when executed, will simply call that function passing
 			// `price` as an argument.
-			Call::submit_new_avg_price(fetcher.symbol.clone(), avg_price)
+			Call::submit_new_avg_price(fetcher.asset_id)
 		});
 
 		for (acc, res) in &results {
@@ -324,38 +592,156 @@ impl<T: Trait> Module<T> {
 		Ok(())
 	}
 
-	fn fetch_price_and_submit(fetcher: Fetcher<T::BlockNumber>) -> Result<(), &'static str> {
-		let signer = Signer::<T, T::AuthorityId>::all_accounts();
-		if !signer.can_sign() {
-			return Err("No local accounts available. Consider adding one via `author_insertKey` RPC.");
+	/// Filters `price_points` through median + MAD-based outlier detection,
+	/// then aggregates the non-outlier samples according to `kind`. Returns
+	/// the resulting price and the authors of the flagged outliers together
+	/// with the price each of them reported.
+	fn aggregate(
+		kind: AggregationKind,
+		price_points: &[FetchedPrice<T::AccountId, T::AssetId>],
+	) -> (Price, Vec<(T::AccountId, Price)>) {
+		let (kept, offenders) = Self::filter_outliers(price_points);
+
+		let avg_price = match kind {
+			AggregationKind::Mean => Self::mean(&kept),
+			AggregationKind::Median => {
+				let mut prices: Vec<Price> = kept.iter().map(|pp| pp.price).collect();
+				median(&mut prices)
+			}
+			AggregationKind::Twap => Self::twap(&kept),
+		};
+
+		(avg_price, offenders)
+	}
+
+	/// Median + MAD-based outlier detection over `price_points`. Returns the
+	/// non-outlier samples and, for each flagged one, its author alongside
+	/// the price they reported.
+	///
+	/// Samples whose absolute deviation from the median exceeds
+	/// `OUTLIER_K * 1.4826 * MAD` are excluded. If `MAD == 0` (every sample
+	/// is identical, or there's only one), nothing is flagged.
+	fn filter_outliers(
+		price_points: &[FetchedPrice<T::AccountId, T::AssetId>],
+	) -> (Vec<FetchedPrice<T::AccountId, T::AssetId>>, Vec<(T::AccountId, Price)>) {
+		let mut prices: Vec<Price> = price_points.iter().map(|pp| pp.price).collect();
+		let median_price = median(&mut prices);
+
+		let mut deviations: Vec<Price> = prices.iter().map(|p| abs_diff(*p, median_price)).collect();
+		let mad = median(&mut deviations);
+
+		let threshold = if mad.is_zero() {
+			None
+		} else {
+			Some(mad.saturating_mul(Price::from(OUTLIER_K)).saturating_mul(
+				Price::saturating_from_rational(MAD_SCALE_NUMERATOR, MAD_SCALE_DENOMINATOR),
+			))
+		};
+
+		let mut kept = Vec::new();
+		let mut offenders = Vec::new();
+		for pp in price_points {
+			let is_outlier = threshold.map_or(false, |t| abs_diff(pp.price, median_price) > t);
+			if is_outlier {
+				offenders.push((pp.author.clone(), pp.price));
+			} else {
+				kept.push(pp.clone());
+			}
 		}
 
-		//NOTE: Blocking http request
-		let fetched_price = Self::fetch_price(fetcher.url).map_err(|_| "Failed to fetch data")?;
+		(kept, offenders)
+	}
 
-		let results = signer.send_signed_transaction(|_account| {
-			// Received price is wrapped into a call to `submit_price` public function of this pallet.
-			// This means that the transaction, when executed, will simply call that function passing
-			// `price` as an argument.
-			Call::submit_new_price(fetched_price.clone())
+	/// Arithmetic mean of `points`.
+	fn mean(points: &[FetchedPrice<T::AccountId, T::AssetId>]) -> Price {
+		if points.is_empty() {
+			return Price::from(0);
+		}
+		let sum = points.iter().fold(Price::from(0), |acc, pp| acc + pp.price);
+		sum / Price::from(points.len() as u128)
+	}
+
+	/// Time-weighted average of `points`: sorted by their `time` field, each
+	/// price is weighted by how long it stayed "in effect" until the next
+	/// sample. Falls back to a plain mean if there's only one effective
+	/// timestamp (a zero total interval); sorting first rules out negative
+	/// per-sample intervals from non-monotonic submissions.
+	fn twap(points: &[FetchedPrice<T::AccountId, T::AssetId>]) -> Price {
+		if points.len() <= 1 {
+			return Self::mean(points);
+		}
+
+		let mut timed: Vec<(u64, Price)> = points
+			.iter()
+			.map(|pp| (u64::decode(&mut &pp.time[..]).unwrap_or(0), pp.price))
+			.collect();
+		timed.sort_by_key(|(t, _)| *t);
+
+		let first = timed[0].0;
+		let last = timed[timed.len() - 1].0;
+		let total_interval = last.saturating_sub(first);
+
+		if total_interval == 0 {
+			return Self::mean(points);
+		}
+
+		let weighted_sum = timed.windows(2).fold(Price::from(0), |acc, w| {
+			let (t_i, price_i) = w[0];
+			let (t_next, _) = w[1];
+			let interval = t_next.saturating_sub(t_i);
+			acc + price_i.saturating_mul(Price::from(interval as u128))
 		});
 
-		for (acc, res) in &results {
-			match res {
-				Ok(()) => debug::info!("New price submitted by [{:?}]", acc.id),
-				Err(e) => debug::error!("[{:?}] Failed to submit transaction: {:?}", acc.id, e),
+		weighted_sum / Price::from(total_interval as u128)
+	}
+
+	fn fetch_price_and_submit(
+		fetcher: Fetcher<T::AccountId, T::AssetId, T::BlockNumber, BalanceOf<T>>,
+		block_number: T::BlockNumber,
+	) -> Result<(), &'static str> {
+		//NOTE: Blocking http requests, one per configured source. Reported via an
+		//unsigned transaction signed with the offchain-worker key, so this needs
+		//no funded account (see `submit_new_price_unsigned` / `ValidateUnsigned`).
+		for source in <Sources<T>>::get(fetcher.asset_id).into_iter() {
+			let source_id = source.source_id;
+			let (price, time) = match Self::fetch_price(&source) {
+				Ok(p) => p,
+				Err(e) => {
+					debug::warn!("Failed to fetch source {} for asset: {:?}", source_id, e);
+					continue;
+				}
+			};
+
+			let asset_id = fetcher.asset_id;
+			let time_for_payload = time.clone();
+			let result = Signer::<T, T::AuthorityId>::any_account().send_unsigned_transaction(
+				|account| PricePayload {
+					block_number,
+					asset_id,
+					source_id,
+					price,
+					time: time_for_payload.clone(),
+					public: account.public.clone(),
+				},
+				|payload, signature| Call::submit_new_price_unsigned(payload, signature),
+			);
+
+			match result {
+				Some((acc, Ok(()))) => debug::info!("New price submitted by [{:?}]", acc.id),
+				Some((acc, Err(e))) => debug::error!("[{:?}] Failed to submit transaction: {:?}", acc.id, e),
+				None => debug::error!("No local accounts available. Consider adding one via `author_insertKey` RPC."),
 			}
 		}
 
 		Ok(())
 	}
 
-	/// Fetch current price from url
-	fn fetch_price(url: Vec<u8>) -> Result<DiaPriceRecord, http::Error> {
+	/// Fetch current `(price, time)` from a single configured source.
+	fn fetch_price(source: &SourceConfig) -> Result<(Price, Vec<u8>), http::Error> {
 		// deadline to complete the external call.
 		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
 
-		let request = http::Request::get(sp_std::str::from_utf8(&url).unwrap());
+		let request = http::Request::get(sp_std::str::from_utf8(&source.url).unwrap());
 		let pending = request.deadline(deadline).send().map_err(|_| http::Error::IoError)?;
 
 		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
@@ -371,24 +757,60 @@ impl<T: Trait> Module<T> {
 			http::Error::Unknown
 		})?;
 
-		let price = match Self::parse_dia_res(body_str) {
-			Some(price) => Ok(price),
-			None => {
-				debug::warn!("Unable to parse response: {:?}", body_str);
-				Err(http::Error::Unknown)
-			}
-		}?;
+		let price = extract_price(body_str, &source.price_path, source.decimals).ok_or_else(|| {
+			debug::warn!("Unable to parse response: {:?}", body_str);
+			http::Error::Unknown
+		})?;
 
-		Ok(price)
+		let time = sp_io::offchain::timestamp().unix_millis().encode();
+
+		Ok((price, time))
 	}
+}
 
-	/// Parse json response body received from dia request
-	///
-	/// Returns `None` when parsing failed or `Some(DiaPriceRecord)` when parsing is successful.
-	fn parse_dia_res(body: &str) -> Option<DiaPriceRecord> {
-		match serde_json::from_str(&body) {
-			Ok(p) => Some(p),
-			Err(_) => None,
+impl<T: Trait> frame_support::unsigned::ValidateUnsigned for Module<T> {
+	type Call = Call<T>;
+
+	/// Validates `submit_new_price_unsigned`: the payload must be signed by a
+	/// key in `T::ValidatorSet::authorities()` (the current session's
+	/// authorities), must not carry a future block number nor one more than
+	/// `GracePeriod` blocks in the past, must target an asset with an
+	/// active `Fetcher`, and must not exceed one report per authority per
+	/// asset per `GracePeriod`-sized block window (enforced via the
+	/// `provides` tag, which rejects duplicates).
+	fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+		if let Call::submit_new_price_unsigned(payload, signature) = call {
+			let signature_valid = SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone());
+			if !signature_valid {
+				return InvalidTransaction::BadProof.into();
+			}
+
+			if !T::ValidatorSet::authorities().contains(&payload.public) {
+				return InvalidTransaction::BadSigner.into();
+			}
+
+			let current_block = <system::Module<T>>::block_number();
+			if payload.block_number > current_block {
+				return InvalidTransaction::Future.into();
+			}
+			if current_block.saturating_sub(payload.block_number) > T::GracePeriod::get() {
+				return InvalidTransaction::Stale.into();
+			}
+
+			if !<Fetchers<T>>::contains_key(&payload.asset_id) {
+				return InvalidTransaction::Stale.into();
+			}
+
+			let block_window = payload.block_number / T::GracePeriod::get();
+
+			ValidTransaction::with_tag_prefix("PriceFetchUnsigned")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((payload.public.clone(), payload.asset_id, block_window))
+				.longevity(T::GracePeriod::get().saturated_into::<u64>())
+				.propagate(true)
+				.build()
+		} else {
+			InvalidTransaction::Call.into()
 		}
 	}
-}
\ No newline at end of file
+}
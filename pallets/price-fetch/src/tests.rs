@@ -0,0 +1,146 @@
+use crate::mock::{new_test_ext, Test};
+use crate::{abs_diff, extract_price, median, FetchedPrice, Module, PricePayload};
+use codec::Encode;
+use frame_support::unsigned::ValidateUnsigned;
+use sp_core::{crypto::AccountId32, Pair};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	FixedPointNumber, MultiSignature, MultiSigner,
+};
+
+type AccountId = <Test as frame_system::Trait>::AccountId;
+
+/// A distinct `AccountId` for each `seed`, for tests that only care that
+/// authors differ, not who they are.
+fn account(seed: u8) -> AccountId {
+	AccountId32::new([seed; 32])
+}
+
+fn sample(price: u128, author: u8) -> FetchedPrice<AccountId, u32> {
+	FetchedPrice {
+		price: primitives::Price::from(price),
+		time: 0u64.encode(),
+		asset_id: 0,
+		source_id: 0,
+		author: account(author),
+	}
+}
+
+fn timed_sample(price: u128, time: u64, author: u8) -> FetchedPrice<AccountId, u32> {
+	FetchedPrice {
+		price: primitives::Price::from(price),
+		time: time.encode(),
+		asset_id: 0,
+		source_id: 0,
+		author: account(author),
+	}
+}
+
+#[test]
+fn median_averages_the_two_middle_elements_for_even_length() {
+	let mut values = vec![
+		primitives::Price::from(1),
+		primitives::Price::from(2),
+		primitives::Price::from(3),
+		primitives::Price::from(4),
+	];
+	assert_eq!(median(&mut values), primitives::Price::saturating_from_rational(5u128, 2u128));
+}
+
+#[test]
+fn median_returns_the_middle_element_for_odd_length() {
+	let mut values = vec![primitives::Price::from(3), primitives::Price::from(1), primitives::Price::from(2)];
+	assert_eq!(median(&mut values), primitives::Price::from(2));
+}
+
+#[test]
+fn median_of_an_empty_slice_is_zero() {
+	let mut values: Vec<primitives::Price> = vec![];
+	assert_eq!(median(&mut values), primitives::Price::from(0));
+}
+
+#[test]
+fn abs_diff_is_order_independent() {
+	let a = primitives::Price::from(5);
+	let b = primitives::Price::from(2);
+	assert_eq!(abs_diff(a, b), primitives::Price::from(3));
+	assert_eq!(abs_diff(b, a), primitives::Price::from(3));
+}
+
+#[test]
+fn extract_price_scales_by_decimals() {
+	let body = r#"{"Price": "123"}"#;
+	// decimals == 0: value is already a human-readable price.
+	assert_eq!(extract_price(body, b"/Price", 0), Some(primitives::Price::from(123)));
+	// decimals == 2: the raw value 12345 stands for 123.45.
+	assert_eq!(
+		extract_price(r#"{"Price": 12345}"#, b"/Price", 2),
+		Some(primitives::Price::saturating_from_rational(12345u128, 100u128))
+	);
+}
+
+#[test]
+fn filter_outliers_excludes_samples_beyond_the_mad_threshold() {
+	new_test_ext().execute_with(|| {
+		let points = vec![sample(100, 1), sample(101, 2), sample(99, 3), sample(10_000, 4)];
+		let (kept, offenders) = Module::<Test>::filter_outliers(&points);
+
+		assert_eq!(offenders, vec![(account(4), primitives::Price::from(10_000))]);
+		assert_eq!(kept.len(), 3);
+		assert!(kept.iter().all(|pp| pp.author != account(4)));
+	});
+}
+
+#[test]
+fn filter_outliers_flags_nothing_when_mad_is_zero() {
+	new_test_ext().execute_with(|| {
+		// Every sample is identical, so MAD == 0 and the threshold check is skipped.
+		let points = vec![sample(100, 1), sample(100, 2), sample(100, 3)];
+		let (kept, offenders) = Module::<Test>::filter_outliers(&points);
+
+		assert!(offenders.is_empty());
+		assert_eq!(kept.len(), 3);
+	});
+}
+
+#[test]
+fn twap_falls_back_to_mean_for_a_single_sample() {
+	new_test_ext().execute_with(|| {
+		let points = vec![timed_sample(100, 0, 1)];
+		assert_eq!(Module::<Test>::twap(&points), primitives::Price::from(100));
+	});
+}
+
+#[test]
+fn twap_falls_back_to_mean_when_the_total_interval_is_zero() {
+	new_test_ext().execute_with(|| {
+		// Both samples share the same timestamp, so the total interval is 0.
+		let points = vec![timed_sample(100, 0, 1), timed_sample(200, 0, 2)];
+		assert_eq!(Module::<Test>::twap(&points), primitives::Price::from(150));
+	});
+}
+
+#[test]
+fn validate_unsigned_rejects_a_payload_signed_by_a_non_authority() {
+	new_test_ext().execute_with(|| {
+		// `type ValidatorSet = ()` in the mock reports no authorities, so even
+		// a self-consistent signature must be rejected.
+		let pair = sp_core::sr25519::Pair::from_seed(&[7u8; 32]);
+		let public = MultiSigner::Sr25519(pair.public());
+
+		let payload = PricePayload::<MultiSigner, u64, u32> {
+			block_number: 0,
+			asset_id: 0,
+			source_id: 0,
+			price: primitives::Price::from(1),
+			time: 0u64.encode(),
+			public,
+		};
+		let signature = MultiSignature::Sr25519(pair.sign(&payload.encode()));
+
+		let call = crate::Call::submit_new_price_unsigned(payload, signature);
+		let result = Module::<Test>::validate_unsigned(TransactionSource::Local, &call);
+
+		assert_eq!(result, Err(InvalidTransaction::BadSigner.into()));
+	});
+}
@@ -0,0 +1,122 @@
+// This file is part of hack.HydraDX-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for price_fetch
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0
+//! DATE: 2021-02-08, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// target/release/hack-hydra-dx
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=price_fetch
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=weights.rs
+// --template=.maintain/pallet-weight-template.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for price_fetch.
+pub trait WeightInfo {
+	fn set_sources(s: u32) -> Weight;
+	fn start_fetcher() -> Weight;
+	fn submit_new_price(s: u32) -> Weight;
+	fn submit_new_price_unsigned(s: u32) -> Weight;
+	fn submit_new_avg_price(s: u32) -> Weight;
+}
+
+/// Weights for price_fetch using the hack.hydraDX node and recommended hardware.
+pub struct HackHydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> WeightInfo for HackHydraWeight<T> {
+	fn set_sources(s: u32) -> Weight {
+		(22_874_000 as Weight)
+			.saturating_add((98_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn start_fetcher() -> Weight {
+		(42_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn submit_new_price(s: u32) -> Weight {
+		(28_331_000 as Weight)
+			.saturating_add((125_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	//NOTE: not produced by the benchmark CLI, as there is no benchmark for this
+	//call; it copies the `submit_new_price` figures and scales with `s` the same
+	//way, since it pushes into the same unbounded `FetchedPrices` vector.
+	fn submit_new_price_unsigned(s: u32) -> Weight {
+		(28_331_000 as Weight)
+			.saturating_add((125_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn submit_new_avg_price(s: u32) -> Weight {
+		(37_552_000 as Weight)
+			.saturating_add((156_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_sources(s: u32) -> Weight {
+		(22_874_000 as Weight)
+			.saturating_add((98_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn start_fetcher() -> Weight {
+		(42_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn submit_new_price(s: u32) -> Weight {
+		(28_331_000 as Weight)
+			.saturating_add((125_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn submit_new_price_unsigned(s: u32) -> Weight {
+		(28_331_000 as Weight)
+			.saturating_add((125_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn submit_new_avg_price(s: u32) -> Weight {
+		(37_552_000 as Weight)
+			.saturating_add((156_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}